@@ -0,0 +1,170 @@
+//! Derive macros for the `byte` crate's `TryRead` and `TryWrite` traits.
+//!
+//! The macros generate the offset bookkeeping otherwise written by hand for
+//! plain structs. Fields are serialized in declaration order; a `#[byte(ctx =
+//! ...)]` attribute selects the per-field context, and a struct-level `#[byte(ctx
+//! = ...)]` names the context type threaded into the generated impl (so an
+//! endian can be forwarded to every numeric field at once).
+//!
+//! The struct-level context is forwarded into each field by value, so a
+//! context type shared by more than one default-context field must be `Copy`
+//! (as every context in `byte::ctx` is). A non-`Copy` context is fine as long
+//! as each field overrides it with its own `#[byte(ctx = ...)]`.
+//!
+//! ```ignore
+//! #[derive(TryRead, TryWrite)]
+//! #[byte(ctx = Endian)]
+//! struct Header<'a> {
+//!     name_len: u16,
+//!     #[byte(ctx = Str::Len(name_len as usize))]
+//!     name: &'a str,
+//!     #[byte(ctx = ())]
+//!     enabled: bool,
+//! }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Expr, Fields, GenericParam, Generics,
+    Lifetime, LifetimeParam, Type,
+};
+
+/// Derive [`TryRead`] for a plain struct.
+#[proc_macro_derive(TryRead, attributes(byte))]
+pub fn derive_try_read(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_try_read(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derive [`TryWrite`] for a plain struct.
+#[proc_macro_derive(TryWrite, attributes(byte))]
+pub fn derive_try_write(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_try_write(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// The context type threaded through the generated impl, `()` by default.
+fn container_ctx(input: &DeriveInput) -> syn::Result<Type> {
+    let mut ctx: Type = parse_quote!(());
+    for attr in &input.attrs {
+        if attr.path().is_ident("byte") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("ctx") {
+                    ctx = meta.value()?.parse()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(ctx)
+}
+
+/// The per-field context expression, defaulting to the forwarded context.
+fn field_ctx(attrs: &[syn::Attribute]) -> syn::Result<Expr> {
+    let mut ctx: Expr = parse_quote!(__byte_ctx);
+    for attr in attrs {
+        if attr.path().is_ident("byte") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("ctx") {
+                    ctx = meta.value()?.parse()?;
+                }
+                Ok(())
+            })?;
+        }
+    }
+    Ok(ctx)
+}
+
+fn named_fields(data: &Data) -> syn::Result<&Fields> {
+    match data {
+        Data::Struct(data) => Ok(&data.fields),
+        _ => Err(syn::Error::new(
+            Span::call_site(),
+            "TryRead/TryWrite can only be derived for structs",
+        )),
+    }
+}
+
+/// Return the struct's first lifetime, inserting a fresh `'a` if it has none.
+fn bytes_lifetime(generics: &mut Generics) -> Lifetime {
+    if let Some(param) = generics.lifetimes().next() {
+        return param.lifetime.clone();
+    }
+    let lifetime = Lifetime::new("'a", Span::call_site());
+    generics
+        .params
+        .insert(0, GenericParam::Lifetime(LifetimeParam::new(lifetime.clone())));
+    lifetime
+}
+
+fn expand_try_read(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let ctx_ty = container_ctx(&input)?;
+    let fields = named_fields(&input.data)?;
+
+    let mut generics = input.generics.clone();
+    let lifetime = bytes_lifetime(&mut generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut reads = Vec::new();
+    let mut names = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().ok_or_else(|| {
+            syn::Error::new(Span::call_site(), "tuple structs are not supported")
+        })?;
+        let ctx = field_ctx(&field.attrs)?;
+        reads.push(quote! {
+            let #ident = ::byte::BytesExt::read_with(__byte_bytes, __byte_offset, #ctx)?;
+        });
+        names.push(ident);
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::byte::TryRead<#lifetime, #ctx_ty> for #name #ty_generics #where_clause {
+            fn try_read(__byte_bytes: &#lifetime [u8], __byte_ctx: #ctx_ty) -> ::byte::Result<(Self, usize)> {
+                let __byte_offset = &mut 0;
+                #(#reads)*
+                Ok((#name { #(#names),* }, *__byte_offset))
+            }
+        }
+    })
+}
+
+fn expand_try_write(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let ctx_ty = container_ctx(&input)?;
+    let fields = named_fields(&input.data)?;
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut writes = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().ok_or_else(|| {
+            syn::Error::new(Span::call_site(), "tuple structs are not supported")
+        })?;
+        let ctx = field_ctx(&field.attrs)?;
+        writes.push(quote! {
+            let #ident = self.#ident;
+            ::byte::BytesExt::write_with(__byte_bytes, __byte_offset, #ident, #ctx)?;
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::byte::TryWrite<#ctx_ty> for #name #ty_generics #where_clause {
+            fn try_write(self, __byte_bytes: &mut [u8], __byte_ctx: #ctx_ty) -> ::byte::Result<usize> {
+                let __byte_offset = &mut 0;
+                #(#writes)*
+                Ok(*__byte_offset)
+            }
+        }
+    })
+}
@@ -1,4 +1,4 @@
-use {check_len, Error, Result, TryRead, TryWrite};
+use {check_len, Error, Result, TryMeasure, TryRead, TryWrite};
 
 impl<'a> TryRead<'a> for bool {
     #[inline]
@@ -24,3 +24,10 @@ impl TryWrite for bool {
         Ok(1)
     }
 }
+
+impl TryMeasure for bool {
+    #[inline]
+    fn try_measure(&self, _ctx: ()) -> Result<usize> {
+        Ok(1)
+    }
+}
@@ -0,0 +1,50 @@
+//! A wrapper that validates an internet checksum while reading.
+
+use checksum::Checksum;
+use {Error, Result, TryRead};
+
+/// Reads an inner `T`, then verifies the RFC 1071 internet checksum over the
+/// exact region it consumed.
+///
+/// The decoded value is carried in the `.0` field; the inner type reads with
+/// whatever context `T` already expects, so the wrapper is transparent to the
+/// framing. On a well-formed packet the one's-complement sum over the bytes
+/// covered by the checksum field is zero; any other value yields
+/// [`Error::BadInput`].
+///
+/// # Example
+///
+/// ```
+/// use byte::*;
+/// use byte::ctx::{Bytes, Checksummed};
+///
+/// // Four 16-bit words whose one's-complement sum is zero: the last word is
+/// // the checksum of the first three.
+/// let bytes: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0xff, 0xf9];
+///
+/// let offset = &mut 0;
+/// let frame = bytes.read_with::<Checksummed<&[u8]>>(offset, Bytes::Len(8)).unwrap();
+/// assert_eq!(frame.0.len(), 8);
+/// assert_eq!(*offset, 8);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Checksummed<T>(pub T);
+
+impl<'a, Ctx, T> TryRead<'a, Ctx> for Checksummed<T>
+where
+    T: TryRead<'a, Ctx>,
+{
+    fn try_read(bytes: &'a [u8], ctx: Ctx) -> Result<(Self, usize)> {
+        let (value, size) = T::try_read(bytes, ctx)?;
+
+        let mut checksum = Checksum::new();
+        checksum.add_bytes(&bytes[..size]);
+        if checksum.checksum() != 0 {
+            return Err(Error::BadInput {
+                err: "Checksum mismatch",
+            });
+        }
+
+        Ok((Checksummed(value), size))
+    }
+}
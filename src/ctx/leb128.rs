@@ -0,0 +1,163 @@
+//! LEB128 variable-length integers.
+//!
+//! [LEB128](https://en.wikipedia.org/wiki/LEB128) is the variable-length
+//! integer encoding used by DWARF, WebAssembly and protobuf-style formats.
+//! [`Uleb128`] carries an unsigned value, [`Sleb128`] a sign-extended signed
+//! value; both read and write through the default `()` context.
+
+use {Error, Result, TryRead, TryWrite};
+
+/// An unsigned LEB128 integer.
+///
+/// # Example
+///
+/// ```
+/// use byte::*;
+/// use byte::ctx::Uleb128;
+///
+/// let bytes: &[u8] = &[0xe5, 0x8e, 0x26];
+///
+/// let offset = &mut 0;
+/// let Uleb128(num) = bytes.read(offset).unwrap();
+/// assert_eq!(num, 624_485);
+/// assert_eq!(*offset, 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Uleb128(pub u64);
+
+/// A signed, sign-extended LEB128 integer.
+///
+/// # Example
+///
+/// ```
+/// use byte::*;
+/// use byte::ctx::Sleb128;
+///
+/// let bytes: &[u8] = &[0x9b, 0xf1, 0x59];
+///
+/// let offset = &mut 0;
+/// let Sleb128(num) = bytes.read(offset).unwrap();
+/// assert_eq!(num, -624_485);
+/// assert_eq!(*offset, 3);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sleb128(pub i64);
+
+impl<'a> TryRead<'a> for Uleb128 {
+    fn try_read(bytes: &'a [u8], _ctx: ()) -> Result<(Self, usize)> {
+        let mut result: u64 = 0;
+        let mut shift = 0;
+        let mut offset = 0;
+
+        loop {
+            let byte = *bytes.get(offset).ok_or(Error::Incomplete)?;
+            offset += 1;
+
+            if shift >= 64 {
+                return Err(Error::BadInput {
+                    err: "Overlong LEB128 sequence",
+                });
+            }
+
+            // The final group for a `u64` lands at shift 63 and may carry only
+            // bit 63; any higher payload bit would be silently dropped by the
+            // shift, so reject it rather than truncate.
+            if shift == 63 && byte & 0x7e != 0 {
+                return Err(Error::BadInput {
+                    err: "Overlong LEB128 sequence",
+                });
+            }
+
+            result |= u64::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok((Uleb128(result), offset));
+            }
+
+            shift += 7;
+        }
+    }
+}
+
+impl TryWrite for Uleb128 {
+    fn try_write(self, bytes: &mut [u8], _ctx: ()) -> Result<usize> {
+        let mut value = self.0;
+        let mut offset = 0;
+
+        loop {
+            let mut byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value != 0 {
+                byte |= 0x80;
+            }
+
+            *bytes.get_mut(offset).ok_or(Error::Incomplete)? = byte;
+            offset += 1;
+
+            if value == 0 {
+                return Ok(offset);
+            }
+        }
+    }
+}
+
+impl<'a> TryRead<'a> for Sleb128 {
+    fn try_read(bytes: &'a [u8], _ctx: ()) -> Result<(Self, usize)> {
+        let mut result: i64 = 0;
+        let mut shift = 0;
+        let mut offset = 0;
+
+        loop {
+            let byte = *bytes.get(offset).ok_or(Error::Incomplete)?;
+            offset += 1;
+
+            if shift >= 64 {
+                return Err(Error::BadInput {
+                    err: "Overlong LEB128 sequence",
+                });
+            }
+
+            // The final group for an `i64` lands at shift 63: its low bit is the
+            // value's sign bit and the remaining payload bits must sign-extend
+            // it, otherwise the encoding overflows `i64`.
+            if shift == 63 {
+                let expected = if byte & 0x01 == 0 { 0x00 } else { 0x7e };
+                if byte & 0x7e != expected {
+                    return Err(Error::BadInput {
+                        err: "Overlong LEB128 sequence",
+                    });
+                }
+            }
+
+            result |= i64::from(byte & 0x7f) << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    result |= !0 << shift;
+                }
+                return Ok((Sleb128(result), offset));
+            }
+        }
+    }
+}
+
+impl TryWrite for Sleb128 {
+    fn try_write(self, bytes: &mut [u8], _ctx: ()) -> Result<usize> {
+        let mut value = self.0;
+        let mut offset = 0;
+
+        loop {
+            let byte = (value as u8) & 0x7f;
+            value >>= 7;
+            let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+
+            *bytes.get_mut(offset).ok_or(Error::Incomplete)? = if done { byte } else { byte | 0x80 };
+            offset += 1;
+
+            if done {
+                return Ok(offset);
+            }
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! Contexts for the language primitives supported out of the box.
+//!
+//! Every primitive reads and writes through a *context* value describing how
+//! the raw bytes should be interpreted, e.g. the [`Endian`] of a number or the
+//! framing of a [`Str`].
+
+use core::mem::size_of;
+use core::str;
+
+use {check_len, BytesExt, Error, Result, TryMeasure, TryRead, TryWrite};
+
+mod bool;
+mod checksummed;
+mod leb128;
+mod zigzag;
+
+pub use self::checksummed::Checksummed;
+pub use self::leb128::{Sleb128, Uleb128};
+pub use self::zigzag::Zigzag;
+
+/// The endianness of numeric primitives.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Endian {
+    /// Big endian, also known as network byte order.
+    Big,
+    /// Little endian.
+    Little,
+}
+
+/// Big endian.
+pub const BE: Endian = Endian::Big;
+/// Little endian.
+pub const LE: Endian = Endian::Little;
+/// Network byte order, an alias of [`BE`].
+pub const NETWORK: Endian = Endian::Big;
+
+#[cfg(target_endian = "little")]
+const NATIVE: Endian = Endian::Little;
+#[cfg(target_endian = "big")]
+const NATIVE: Endian = Endian::Big;
+
+impl Default for Endian {
+    #[inline]
+    fn default() -> Self {
+        NATIVE
+    }
+}
+
+macro_rules! num_impl {
+    ($ty: ty) => {
+        impl<'a> TryRead<'a, Endian> for $ty {
+            #[inline]
+            fn try_read(bytes: &'a [u8], endian: Endian) -> Result<(Self, usize)> {
+                let size = size_of::<$ty>();
+                check_len(bytes, size)?;
+
+                let mut buf = [0u8; size_of::<$ty>()];
+                buf.copy_from_slice(&bytes[..size]);
+                let val = match endian {
+                    Endian::Big => <$ty>::from_be_bytes(buf),
+                    Endian::Little => <$ty>::from_le_bytes(buf),
+                };
+
+                Ok((val, size))
+            }
+        }
+
+        impl TryWrite<Endian> for $ty {
+            #[inline]
+            fn try_write(self, bytes: &mut [u8], endian: Endian) -> Result<usize> {
+                let size = size_of::<$ty>();
+                check_len(bytes, size)?;
+
+                let buf = match endian {
+                    Endian::Big => self.to_be_bytes(),
+                    Endian::Little => self.to_le_bytes(),
+                };
+                bytes[..size].copy_from_slice(&buf);
+
+                Ok(size)
+            }
+        }
+
+        impl TryMeasure<Endian> for $ty {
+            #[inline]
+            fn try_measure(&self, _endian: Endian) -> Result<usize> {
+                Ok(size_of::<$ty>())
+            }
+        }
+    };
+}
+
+num_impl!(u8);
+num_impl!(u16);
+num_impl!(u32);
+num_impl!(u64);
+num_impl!(u128);
+num_impl!(i8);
+num_impl!(i16);
+num_impl!(i32);
+num_impl!(i64);
+num_impl!(i128);
+num_impl!(f32);
+num_impl!(f64);
+
+/// The `NULL` byte, `\0`, a common string delimiter.
+pub const NULL: u8 = 0;
+/// The space byte, `' '`.
+pub const SPACE: u8 = 0x20;
+/// The line-feed byte, `'\n'`.
+pub const RET: u8 = 0x0a;
+/// The tab byte, `'\t'`.
+pub const TAB: u8 = 0x09;
+
+/// The integer width of a [`Str::LenPrefixed`] length field.
+#[derive(Debug, Clone, Copy)]
+pub enum LenSize {
+    /// A single-byte `u8` length prefix.
+    U8,
+    /// A two-byte `u16` length prefix.
+    U16,
+    /// A four-byte `u32` length prefix.
+    U32,
+}
+
+/// Context for reading and writing `&str`.
+///
+/// # Example
+///
+/// [`Str::LenPrefixed`] writes a length prefix of the chosen width and reads
+/// it back symmetrically; a string too long for the prefix is rejected with
+/// [`Error::BadInput`].
+///
+/// ```
+/// use byte::*;
+/// use byte::ctx::{Str, LenSize};
+///
+/// let mut bytes = [0u8; 8];
+/// let offset = &mut 0;
+/// bytes.write_with::<&str>(offset, "hi", Str::LenPrefixed(LenSize::U16, BE)).unwrap();
+/// assert_eq!(&bytes[..*offset], &[0, 2, b'h', b'i']);
+///
+/// let offset = &mut 0;
+/// let read = bytes.read_with::<&str>(offset, Str::LenPrefixed(LenSize::U16, BE)).unwrap();
+/// assert_eq!(read, "hi");
+/// assert_eq!(*offset, 4);
+///
+/// let long = core::str::from_utf8(&[b'x'; 256]).unwrap();
+/// let err = bytes.write_with::<&str>(&mut 0, long, Str::LenPrefixed(LenSize::U8, BE));
+/// assert_eq!(err, Err(Error::BadInput { err: "String too long for u8 length prefix" }));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub enum Str {
+    /// Read a fixed number of UTF-8 bytes.
+    Len(usize),
+    /// Read until the given delimiter byte, consuming it but leaving it out of
+    /// the result.
+    Delimiter(u8),
+    /// Read until the delimiter byte or at most `len` bytes, whichever comes
+    /// first.
+    DelimiterUntil(u8, usize),
+    /// Read an integer length prefix of the given width and endianness, then
+    /// that many UTF-8 bytes.
+    LenPrefixed(LenSize, Endian),
+}
+
+impl<'a> TryRead<'a, Str> for &'a str {
+    fn try_read(bytes: &'a [u8], ctx: Str) -> Result<(Self, usize)> {
+        let (start, read, consumed) = match ctx {
+            Str::Len(len) => {
+                check_len(bytes, len)?;
+                (0, len, len)
+            }
+            Str::Delimiter(delimiter) => {
+                let position = bytes
+                    .iter()
+                    .position(|&b| b == delimiter)
+                    .ok_or(Error::Incomplete)?;
+                (0, position, position + 1)
+            }
+            Str::DelimiterUntil(delimiter, len) => match bytes
+                .iter()
+                .take(len)
+                .position(|&b| b == delimiter)
+            {
+                Some(position) => (0, position, position + 1),
+                None => {
+                    check_len(bytes, len)?;
+                    (0, len, len)
+                }
+            },
+            Str::LenPrefixed(size, endian) => {
+                let offset = &mut 0;
+                let len = match size {
+                    LenSize::U8 => bytes.read_with::<u8>(offset, endian)? as usize,
+                    LenSize::U16 => bytes.read_with::<u16>(offset, endian)? as usize,
+                    LenSize::U32 => bytes.read_with::<u32>(offset, endian)? as usize,
+                };
+                check_len(&bytes[*offset..], len)?;
+                (*offset, len, *offset + len)
+            }
+        };
+
+        let str = str::from_utf8(&bytes[start..start + read]).map_err(|_| Error::BadInput {
+            err: "Invalid UTF-8 encoding",
+        })?;
+
+        Ok((str, consumed))
+    }
+}
+
+impl TryWrite for &str {
+    #[inline]
+    fn try_write(self, bytes: &mut [u8], _ctx: ()) -> Result<usize> {
+        let str_bytes = self.as_bytes();
+        check_len(bytes, str_bytes.len())?;
+
+        bytes[..str_bytes.len()].copy_from_slice(str_bytes);
+
+        Ok(str_bytes.len())
+    }
+}
+
+impl TryWrite<Str> for &str {
+    fn try_write(self, bytes: &mut [u8], ctx: Str) -> Result<usize> {
+        let offset = &mut 0;
+
+        match ctx {
+            Str::LenPrefixed(size, endian) => {
+                let len = self.len();
+                match size {
+                    LenSize::U8 if len > u8::MAX as usize => {
+                        return Err(Error::BadInput {
+                            err: "String too long for u8 length prefix",
+                        });
+                    }
+                    LenSize::U16 if len > u16::MAX as usize => {
+                        return Err(Error::BadInput {
+                            err: "String too long for u16 length prefix",
+                        });
+                    }
+                    LenSize::U32 if len > u32::MAX as usize => {
+                        return Err(Error::BadInput {
+                            err: "String too long for u32 length prefix",
+                        });
+                    }
+                    LenSize::U8 => bytes.write_with::<u8>(offset, len as u8, endian)?,
+                    LenSize::U16 => bytes.write_with::<u16>(offset, len as u16, endian)?,
+                    LenSize::U32 => bytes.write_with::<u32>(offset, len as u32, endian)?,
+                }
+            }
+            Str::Delimiter(_)
+            | Str::DelimiterUntil(_, _)
+            | Str::Len(_) => {}
+        }
+
+        let body = self.as_bytes();
+        check_len(&bytes[*offset..], body.len())?;
+        bytes[*offset..*offset + body.len()].copy_from_slice(body);
+        *offset += body.len();
+
+        if let Str::Delimiter(delimiter) | Str::DelimiterUntil(delimiter, _) = ctx {
+            check_len(&bytes[*offset..], 1)?;
+            bytes[*offset] = delimiter;
+            *offset += 1;
+        }
+
+        Ok(*offset)
+    }
+}
+
+impl TryMeasure for &str {
+    #[inline]
+    fn try_measure(&self, _ctx: ()) -> Result<usize> {
+        Ok(self.len())
+    }
+}
+
+/// Context for reading and writing `&[u8]`.
+#[derive(Debug, Clone, Copy)]
+pub enum Bytes {
+    /// Read a fixed number of bytes.
+    Len(usize),
+    /// Read until the given pattern is found, consuming it but leaving it out
+    /// of the result.
+    Pattern(&'static [u8]),
+}
+
+impl<'a> TryRead<'a, Bytes> for &'a [u8] {
+    fn try_read(bytes: &'a [u8], ctx: Bytes) -> Result<(Self, usize)> {
+        let (read, consumed) = match ctx {
+            Bytes::Len(len) => {
+                check_len(bytes, len)?;
+                (len, len)
+            }
+            Bytes::Pattern(pattern) => {
+                if pattern.is_empty() {
+                    return Err(Error::BadInput {
+                        err: "Pattern is empty",
+                    });
+                }
+                let position = bytes
+                    .windows(pattern.len())
+                    .position(|window| window == pattern)
+                    .ok_or(Error::Incomplete)?;
+                (position, position + pattern.len())
+            }
+        };
+
+        Ok((&bytes[..read], consumed))
+    }
+}
+
+impl TryWrite for &[u8] {
+    #[inline]
+    fn try_write(self, bytes: &mut [u8], _ctx: ()) -> Result<usize> {
+        check_len(bytes, self.len())?;
+
+        bytes[..self.len()].copy_from_slice(self);
+
+        Ok(self.len())
+    }
+}
+
+impl TryMeasure for &[u8] {
+    #[inline]
+    fn try_measure(&self, _ctx: ()) -> Result<usize> {
+        Ok(self.len())
+    }
+}
@@ -0,0 +1,65 @@
+//! Zigzag-encoded signed variable-length integers.
+//!
+//! Zigzag mapping keeps small-magnitude negative numbers compact by
+//! interleaving them with the positives before emitting the result as
+//! [`Uleb128`]. It is the signed-integer encoding of Thrift's compact protocol
+//! and of protobuf, as opposed to the sign-extending [`Sleb128`].
+//!
+//! [`Sleb128`]: struct.Sleb128.html
+
+use ctx::Uleb128;
+use {Error, Result, TryRead, TryWrite};
+
+/// A zigzag-encoded signed integer, generic over the signed width.
+///
+/// # Example
+///
+/// ```
+/// use byte::*;
+/// use byte::ctx::Zigzag;
+///
+/// let bytes = &mut [0u8; 4];
+///
+/// let offset = &mut 0;
+/// bytes.write(offset, Zigzag(-2i32)).unwrap();
+/// assert_eq!(&bytes[..*offset], &[0x03]);
+///
+/// let offset = &mut 0;
+/// let Zigzag(num): Zigzag<i32> = bytes.read(offset).unwrap();
+/// assert_eq!(num, -2);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Zigzag<T>(pub T);
+
+macro_rules! zigzag_impl {
+    ($signed: ty, $unsigned: ty, $bits: expr) => {
+        impl<'a> TryRead<'a> for Zigzag<$signed> {
+            fn try_read(bytes: &'a [u8], _ctx: ()) -> Result<(Self, usize)> {
+                let (Uleb128(value), size) = TryRead::try_read(bytes, ())?;
+                // The decoded varint must fit the target unsigned width;
+                // otherwise a wider value would silently truncate into a wrong
+                // number. The round-trip cast catches any dropped high bits.
+                let narrowed = value as $unsigned;
+                if narrowed as u64 != value {
+                    return Err(Error::BadInput {
+                        err: "Zigzag value exceeds target width",
+                    });
+                }
+                let num = (narrowed >> 1) as $signed ^ -((narrowed & 1) as $signed);
+                Ok((Zigzag(num), size))
+            }
+        }
+
+        impl TryWrite for Zigzag<$signed> {
+            fn try_write(self, bytes: &mut [u8], _ctx: ()) -> Result<usize> {
+                let num = self.0;
+                let zigzag = ((num << 1) ^ (num >> ($bits - 1))) as $unsigned;
+                Uleb128(u64::from(zigzag)).try_write(bytes, ())
+            }
+        }
+    };
+}
+
+zigzag_impl!(i16, u16, 16);
+zigzag_impl!(i32, u32, 32);
+zigzag_impl!(i64, u64, 64);
@@ -0,0 +1,94 @@
+//! `std::io` bridge for the `TryRead`/`TryWrite` contexts.
+//!
+//! This module is gated behind the `std` feature so the crate stays `no_std`
+//! by default. It mirrors scroll's `IOread`/`IOwrite`, letting the same custom
+//! protocol types that read and write in-memory slices also operate directly
+//! on sockets and files.
+
+use std::io::{Read, Write};
+
+use {Error, Result, TryRead, TryWrite};
+
+/// Size of the stack scratch buffer used to stage a single value.
+const SCRATCH: usize = 256;
+
+/// Extends any [`Read`] with a context-aware typed read.
+pub trait IORead: Read {
+    /// Read a value of type `T` from this reader using `ctx`.
+    ///
+    /// Bytes are pulled into a small stack buffer one at a time and fed to
+    /// `T::try_read`, reading another byte whenever the decode reports
+    /// [`Error::Incomplete`] or [`Error::BadOffset`] (the latter is what a type
+    /// built on `read_with` reports when its buffer ends early). Reading a single byte per step guarantees that no
+    /// byte past the value is consumed from the stream, so sequential reads stay
+    /// aligned (the reader is left positioned exactly after the decoded value).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use byte::*;
+    /// use byte::io::{IORead, IOWrite};
+    ///
+    /// let mut buf = [0u8; 8];
+    /// {
+    ///     let mut writer: &mut [u8] = &mut buf;
+    ///     writer.io_write_with::<u32, _>(0xdeadbeef, BE).unwrap();
+    /// }
+    ///
+    /// // Two values packed back to back are read without corrupting the second.
+    /// let mut reader: &[u8] = &buf;
+    /// let first: u16 = reader.io_read_with(BE).unwrap();
+    /// let second: u16 = reader.io_read_with(BE).unwrap();
+    /// assert_eq!(first, 0xdead);
+    /// assert_eq!(second, 0xbeef);
+    /// ```
+    fn io_read_with<T, Ctx>(&mut self, ctx: Ctx) -> Result<T>
+    where
+        T: for<'a> TryRead<'a, Ctx>,
+        Ctx: Clone,
+    {
+        let mut buf = [0u8; SCRATCH];
+        let mut filled = 0;
+
+        loop {
+            match TryRead::try_read(&buf[..filled], ctx.clone()) {
+                Ok((value, _)) => return Ok(value),
+                Err(Error::Incomplete) | Err(Error::BadOffset(_)) => {}
+                Err(err) => return Err(err),
+            }
+
+            if filled == buf.len() {
+                return Err(Error::Incomplete);
+            }
+
+            // Pull exactly one more byte so the decode never sees — and the
+            // stream never loses — bytes belonging to the next value.
+            let read = self.read(&mut buf[filled..filled + 1])?;
+            if read == 0 {
+                return Err(Error::Incomplete);
+            }
+            filled += read;
+        }
+    }
+}
+
+impl<R: Read + ?Sized> IORead for R {}
+
+/// Extends any [`Write`] with a context-aware typed write.
+pub trait IOWrite: Write {
+    /// Serialize `value` with `ctx` and push the bytes to this writer.
+    ///
+    /// The value is first written into a stack scratch buffer and then handed
+    /// to [`Write::write_all`]; the number of bytes written is returned.
+    fn io_write_with<T, Ctx>(&mut self, value: T, ctx: Ctx) -> Result<usize>
+    where
+        T: TryWrite<Ctx>,
+    {
+        let mut buf = [0u8; SCRATCH];
+        let size = TryWrite::try_write(value, &mut buf, ctx)?;
+        self.write_all(&buf[..size])?;
+        Ok(size)
+    }
+}
+
+impl<W: Write + ?Sized> IOWrite for W {}
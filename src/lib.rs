@@ -122,7 +122,7 @@
 //!         let offset = &mut 0;
 //!
 //!         bytes.write_with::<u16>(offset, self.name.len() as u16, endian)?;
-//!         bytes.write::<&str>(offset, self.name)?;
+//!         bytes.write_with::<&str>(offset, self.name, ())?;
 //!         bytes.write::<bool>(offset, self.enabled)?;
 //!
 //!         Ok(*offset)
@@ -146,7 +146,21 @@
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "derive")]
+extern crate byte_derive;
+
+/// Derive macros for `TryRead` and `TryWrite`, available with the `derive`
+/// feature.
+#[cfg(feature = "derive")]
+pub use byte_derive::{TryRead, TryWrite};
+
+pub mod checksum;
 pub mod ctx;
+#[cfg(feature = "std")]
+pub mod io;
 use core::marker::PhantomData;
 pub use ctx::{BE, LE};
 
@@ -156,7 +170,7 @@ pub type Result<T> = core::result::Result<T, Error>;
 /// The error type for serializing and deserializing.
 ///
 /// - `Error::BadOffset` should only raised in `bytes.read()` and `bytes.write()`
-/// when offset exceeded slice's length.
+///   when offset exceeded slice's length.
 ///
 /// - `Error::BadInput` and `Error::Incomplete` should only raised in `try_read()` and `try_write()`.
 ///
@@ -170,6 +184,22 @@ pub enum Error {
     BadOffset(usize),
     /// The requested data content is invalid
     BadInput { err: &'static str },
+    /// An error from the underlying reader or writer
+    ///
+    /// Only raised by the `std`-gated `io` bridge. This carries the
+    /// `std::io::ErrorKind` rather than the full `std::io::Error` so that
+    /// `Error` keeps its `Copy`/`Clone`/`PartialEq`/`Eq` derives regardless of
+    /// which features are enabled.
+    #[cfg(feature = "std")]
+    Io(::std::io::ErrorKind),
+}
+
+#[cfg(feature = "std")]
+impl From<::std::io::Error> for Error {
+    #[inline]
+    fn from(err: ::std::io::Error) -> Self {
+        Error::Io(err.kind())
+    }
 }
 
 /// A shorthand function to check whether the given length
@@ -254,6 +284,38 @@ pub trait TryWrite<Ctx = ()> {
     fn try_write(self, bytes: &mut [u8], ctx: Ctx) -> Result<usize>;
 }
 
+/// A data structure whose serialized length can be computed without writing.
+///
+/// This mirrors [`TryWrite`] but only reports how many bytes `try_write` would
+/// consume, letting callers size a buffer exactly before allocating or writing
+/// it. The context is the same one `try_write` takes, since the length of many
+/// types depends on it (e.g. the byte width of an integer given an `Endian`).
+///
+/// # Example
+///
+/// ```
+/// use byte::*;
+///
+/// // The measurement of a slice of measurable values is the sum of theirs.
+/// let strings: &[&str] = &["hello", "world"];
+/// assert_eq!(strings.try_measure(()), Ok(10));
+/// ```
+pub trait TryMeasure<Ctx = ()> {
+    /// Compute the number of bytes this value occupies when written with `ctx`.
+    fn try_measure(&self, ctx: Ctx) -> Result<usize>;
+}
+
+impl<Ctx, T> TryMeasure<Ctx> for [T]
+where
+    T: TryMeasure<Ctx>,
+    Ctx: Clone,
+{
+    #[inline]
+    fn try_measure(&self, ctx: Ctx) -> Result<usize> {
+        self.iter().map(|item| item.try_measure(ctx.clone())).sum()
+    }
+}
+
 /// Extension methods for byte slices.
 ///
 /// # Offset
@@ -261,7 +323,7 @@ pub trait TryWrite<Ctx = ()> {
 /// The first parameter of each method is offset,
 /// instructing the position to begin,
 /// which will be increaed by size the operation consumed.
-pub trait BytesExt<Ctx> {
+pub trait BytesExt<Ctx = ()> {
     /// Read value from byte slice by default context
     ///
     /// # Example
@@ -377,7 +439,7 @@ impl<Ctx> BytesExt<Ctx> for [u8] {
     where
         T: TryRead<'a, Ctx>,
     {
-        let slice = self.as_ref();
+        let slice = self;
 
         if *offset >= slice.len() {
             return Err(Error::BadOffset(*offset));
@@ -399,9 +461,9 @@ impl<Ctx> BytesExt<Ctx> for [u8] {
         Ctx: Clone,
     {
         Iter {
-            bytes: self.as_ref(),
-            offset: offset,
-            ctx: ctx,
+            bytes: self,
+            offset,
+            ctx,
             phantom: PhantomData,
         }
     }
@@ -410,7 +472,7 @@ impl<Ctx> BytesExt<Ctx> for [u8] {
     where
         T: TryWrite<Ctx>,
     {
-        let slice = self.as_mut();
+        let slice = self;
 
         if *offset >= slice.len() {
             return Err(Error::BadOffset(*offset));
@@ -0,0 +1,52 @@
+extern crate byte;
+
+use byte::checksum::Checksum;
+use byte::ctx::{Bytes, Checksummed};
+use byte::{BytesExt, Error};
+
+#[test]
+fn checksum_known_vector() {
+    let mut checksum = Checksum::new();
+    checksum.add_bytes(&[0x00, 0x01, 0xf2, 0x03, 0xf4, 0xf5, 0xf6, 0xf7]);
+    assert_eq!(checksum.checksum(), 0x220d);
+}
+
+#[test]
+fn checksum_odd_split_matches_contiguous() {
+    // A trailing byte from one call must pair with the first byte of the next,
+    // so a split at an odd boundary yields the same sum as one contiguous call.
+    let data = [0x45, 0x00, 0x00, 0x73, 0x00];
+
+    let mut whole = Checksum::new();
+    whole.add_bytes(&data);
+
+    let mut split = Checksum::new();
+    split.add_bytes(&data[..3]);
+    split.add_bytes(&data[3..]);
+
+    assert_eq!(whole.checksum(), split.checksum());
+}
+
+#[test]
+fn checksummed_accepts_balanced_frame() {
+    let bytes: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0xff, 0xf9];
+    let frame = bytes
+        .read_with::<Checksummed<&[u8]>>(&mut 0, Bytes::Len(8))
+        .unwrap();
+    assert_eq!(frame.0.len(), 8);
+}
+
+#[test]
+fn checksummed_rejects_corrupted_frame() {
+    // Flip a byte so the one's-complement sum is no longer zero.
+    let bytes: &[u8] = &[0x00, 0x01, 0x00, 0x02, 0x00, 0x03, 0xff, 0xfa];
+    let err = bytes
+        .read_with::<Checksummed<&[u8]>>(&mut 0, Bytes::Len(8))
+        .err();
+    assert_eq!(
+        err,
+        Some(Error::BadInput {
+            err: "Checksum mismatch",
+        })
+    );
+}
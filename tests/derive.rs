@@ -0,0 +1,42 @@
+#![cfg(feature = "derive")]
+
+extern crate byte;
+
+use byte::ctx::{Endian, Str};
+use byte::{BytesExt, TryRead, TryWrite, BE};
+
+#[derive(Debug, PartialEq, Eq, TryRead, TryWrite)]
+#[byte(ctx = Endian)]
+struct Header<'a> {
+    name_len: u16,
+    #[byte(ctx = Str::Len(name_len as usize))]
+    name: &'a str,
+    #[byte(ctx = ())]
+    enabled: bool,
+}
+
+#[test]
+fn derive_header_round_trip() {
+    let header = Header {
+        name_len: 3,
+        name: "abc",
+        enabled: true,
+    };
+
+    let mut bytes = [0u8; 16];
+    let offset = &mut 0;
+    bytes.write_with(offset, header, BE).unwrap();
+    let written = *offset;
+
+    let offset = &mut 0;
+    let read: Header = bytes.read_with(offset, BE).unwrap();
+    assert_eq!(
+        read,
+        Header {
+            name_len: 3,
+            name: "abc",
+            enabled: true,
+        }
+    );
+    assert_eq!(*offset, written);
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "std")]
+
+extern crate byte;
+
+use byte::io::{IORead, IOWrite};
+use byte::BE;
+
+#[test]
+fn io_round_trip_packed_values() {
+    let mut buf = [0u8; 8];
+    {
+        let mut writer: &mut [u8] = &mut buf;
+        writer.io_write_with::<u32, _>(0xdead_beef, BE).unwrap();
+    }
+
+    // Two u16s packed back to back are read without over-reading the first.
+    let mut reader: &[u8] = &buf;
+    let first: u16 = reader.io_read_with(BE).unwrap();
+    let second: u16 = reader.io_read_with(BE).unwrap();
+    assert_eq!(first, 0xdead);
+    assert_eq!(second, 0xbeef);
+}
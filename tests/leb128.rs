@@ -0,0 +1,82 @@
+extern crate byte;
+
+use byte::ctx::{Sleb128, Uleb128};
+use byte::{BytesExt, Error};
+
+#[test]
+fn uleb128_round_trip() {
+    for value in [0u64, 1, 127, 128, 624_485, u64::MAX].iter().copied() {
+        let mut bytes = [0u8; 10];
+        let offset = &mut 0;
+        bytes.write(offset, Uleb128(value)).unwrap();
+        let written = *offset;
+
+        let offset = &mut 0;
+        let Uleb128(read) = bytes.read(offset).unwrap();
+        assert_eq!(read, value);
+        assert_eq!(*offset, written);
+    }
+}
+
+#[test]
+fn uleb128_incomplete_when_continuation_never_ends() {
+    let bytes: &[u8] = &[0x80];
+    assert_eq!(bytes.read::<Uleb128>(&mut 0), Err(Error::Incomplete));
+}
+
+#[test]
+fn uleb128_rejects_overlong_sequence() {
+    // Eleven groups cannot fit in 64 bits.
+    let bytes: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00];
+    assert_eq!(
+        bytes.read::<Uleb128>(&mut 0),
+        Err(Error::BadInput {
+            err: "Overlong LEB128 sequence",
+        })
+    );
+}
+
+#[test]
+fn uleb128_rejects_overflowing_final_byte() {
+    // The tenth byte may carry only bit 63; bit 1 here would be truncated.
+    let bytes: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02];
+    assert_eq!(
+        bytes.read::<Uleb128>(&mut 0),
+        Err(Error::BadInput {
+            err: "Overlong LEB128 sequence",
+        })
+    );
+}
+
+#[test]
+fn sleb128_round_trip() {
+    for value in [0i64, -1, 1, 63, -64, -624_485, i64::MIN, i64::MAX].iter().copied() {
+        let mut bytes = [0u8; 10];
+        let offset = &mut 0;
+        bytes.write(offset, Sleb128(value)).unwrap();
+
+        let offset = &mut 0;
+        let Sleb128(read) = bytes.read(offset).unwrap();
+        assert_eq!(read, value);
+    }
+}
+
+#[test]
+fn sleb128_sign_extends_high_bit() {
+    // A single byte with bit 6 set is negative after sign extension.
+    let bytes: &[u8] = &[0x7f];
+    let Sleb128(read) = bytes.read(&mut 0).unwrap();
+    assert_eq!(read, -1);
+}
+
+#[test]
+fn sleb128_rejects_overflowing_final_byte() {
+    // Tenth byte with payload that does not sign-extend bit 63.
+    let bytes: &[u8] = &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x40];
+    assert_eq!(
+        bytes.read::<Sleb128>(&mut 0),
+        Err(Error::BadInput {
+            err: "Overlong LEB128 sequence",
+        })
+    );
+}
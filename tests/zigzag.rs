@@ -0,0 +1,46 @@
+extern crate byte;
+
+use byte::ctx::Zigzag;
+use byte::{BytesExt, Error};
+
+#[test]
+fn zigzag_i16_round_trip() {
+    for value in [0i16, -1, 1, i16::MIN, i16::MAX].iter().copied() {
+        let mut bytes = [0u8; 4];
+        bytes.write(&mut 0, Zigzag(value)).unwrap();
+        let Zigzag(read): Zigzag<i16> = bytes.read(&mut 0).unwrap();
+        assert_eq!(read, value);
+    }
+}
+
+#[test]
+fn zigzag_i32_round_trip() {
+    for value in [0i32, -1, 1, i32::MIN, i32::MAX].iter().copied() {
+        let mut bytes = [0u8; 5];
+        bytes.write(&mut 0, Zigzag(value)).unwrap();
+        let Zigzag(read): Zigzag<i32> = bytes.read(&mut 0).unwrap();
+        assert_eq!(read, value);
+    }
+}
+
+#[test]
+fn zigzag_i64_round_trip() {
+    for value in [0i64, -1, 1, i64::MIN, i64::MAX].iter().copied() {
+        let mut bytes = [0u8; 10];
+        bytes.write(&mut 0, Zigzag(value)).unwrap();
+        let Zigzag(read): Zigzag<i64> = bytes.read(&mut 0).unwrap();
+        assert_eq!(read, value);
+    }
+}
+
+#[test]
+fn zigzag_rejects_value_wider_than_target() {
+    // A varint decoding to 65_536 does not fit the 16-bit zigzag target.
+    let bytes: &[u8] = &[0x80, 0x80, 0x04];
+    assert_eq!(
+        bytes.read::<Zigzag<i16>>(&mut 0),
+        Err(Error::BadInput {
+            err: "Zigzag value exceeds target width",
+        })
+    );
+}